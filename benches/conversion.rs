@@ -0,0 +1,80 @@
+//! Compares the two `generate_sql` paths on a large CSV: the string path, which
+//! validates UTF-8 and allocates a `String` per field so it can detect numeric and
+//! boolean values (`--typed`), against the `ByteRecord` fast path, which copies the
+//! field bytes straight into the writer. Run with `cargo bench`.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use roma::process_csv;
+use roma::Arguments;
+
+const ROWS: usize = 2_000_000;
+
+/// Writes a multi-million-row CSV to a temporary path and returns it.
+fn write_sample_csv() -> PathBuf {
+    let path = std::env::temp_dir().join("roma_bench_sample.csv");
+    let mut writer = BufWriter::new(File::create(&path).expect("Unable to create sample CSV"));
+
+    writeln!(writer, "id,name,active,score").unwrap();
+    for row in 0..ROWS {
+        writeln!(writer, "{},name-{},true,{}.5", row, row, row % 100).unwrap();
+    }
+
+    path
+}
+
+fn arguments(source: &Path, target: &str, typed: bool, fast: bool) -> Arguments {
+    Arguments {
+        source             : source.to_string_lossy().to_string(),
+        target             : target.to_string(),
+        target_type        : String::from("sql"),
+        delimiter          : b',',
+        has_headers        : true,
+        table              : String::from("sample"),
+        columns            : Vec::new(),
+        chunk              : 0,
+        chunk_insert       : 0,
+        prefix             : String::new(),
+        suffix             : String::new(),
+        with_transaction   : false,
+        typed,
+        schema             : Vec::new(),
+        bool_as_int        : false,
+        db                 : String::new(),
+        infer              : false,
+        ndjson             : false,
+        source_compression : None,
+        target_compression : None,
+        trim               : String::from("none"),
+        skip_lines         : 0,
+        comment            : None,
+        quote              : None,
+        flexible           : false,
+        fast,
+    }
+}
+
+fn bench_paths(c: &mut Criterion) {
+    let source = write_sample_csv();
+    let target = std::env::temp_dir().join("roma_bench_out.sql");
+    let target = target.to_string_lossy().to_string();
+
+    let mut group = c.benchmark_group("generate_sql");
+
+    group.bench_function("string_path_typed", |b| {
+        b.iter(|| process_csv(arguments(&source, &target, true, false)).unwrap());
+    });
+
+    group.bench_function("byte_path_fast", |b| {
+        b.iter(|| process_csv(arguments(&source, &target, false, true)).unwrap());
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_paths);
+criterion_main!(benches);
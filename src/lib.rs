@@ -1,17 +1,224 @@
 pub struct Arguments {
-    pub source           : String,
-    pub target           : String,
-    pub target_type      : String,
-    pub delimiter        : u8,
-    pub has_headers      : bool,
-    pub table            : String,
-    pub columns          : Vec<String>,
-    pub chunk            : usize,
-    pub chunk_insert     : usize,
-    pub prefix           : String,
-    pub suffix           : String,
-    pub with_transaction : bool,
-    pub typed            : bool,
+    pub source             : String,
+    pub target             : String,
+    pub target_type        : String,
+    pub delimiter          : u8,
+    pub has_headers        : bool,
+    pub table              : String,
+    pub columns            : Vec<String>,
+    pub chunk              : usize,
+    pub chunk_insert       : usize,
+    pub prefix             : String,
+    pub suffix             : String,
+    pub with_transaction   : bool,
+    pub typed              : bool,
+    pub schema             : Vec<Column>,
+    pub bool_as_int        : bool,
+    pub db                 : String,
+    pub infer              : bool,
+    pub ndjson             : bool,
+    pub source_compression : Option<Compression>,
+    pub target_compression : Option<Compression>,
+    pub trim               : String,
+    pub skip_lines         : usize,
+    pub comment            : Option<u8>,
+    pub quote              : Option<u8>,
+    pub flexible           : bool,
+    pub fast               : bool,
+}
+
+/// Compression codec applied transparently to the source or the generated SQL file.
+/// `None` in `Arguments::source_compression`/`Arguments::target_compression` means
+/// "auto-detect from the file extension"; the two sides are resolved independently
+/// so an explicit codec for one doesn't force-decode the other.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    /// Infers the codec from a file name's extension, defaulting to no compression.
+    pub fn detect(path: &str) -> Compression {
+        if path.ends_with(".gz") {
+            Compression::Gzip
+        } else if path.ends_with(".zst") {
+            Compression::Zstd
+        } else {
+            Compression::None
+        }
+    }
+}
+
+/// Parses the `gzip | zstd | none` value of a `--source-compression`/
+/// `--target-compression` flag, leaving the codec unset (auto-detect) when absent
+/// or unrecognised.
+fn parse_compression(value: Option<&str>) -> Option<Compression> {
+    match value {
+        Some("gzip") => Some(Compression::Gzip),
+        Some("zstd") => Some(Compression::Zstd),
+        Some("none") => Some(Compression::None),
+        _            => None,
+    }
+}
+
+/// Opens `path` for reading, wrapping it in the matching decoder so the rest of the
+/// pipeline sees a plain byte stream. Decoding is streaming and constant-memory.
+pub fn open_reader(path: &str, compression: Compression) -> Result<Box<dyn std::io::Read>, std::io::Error> {
+    let file = std::fs::File::open(path)?;
+    Ok(match compression {
+        Compression::Gzip => Box::new(flate2::read::GzDecoder::new(file)),
+        Compression::Zstd => Box::new(zstd::stream::read::Decoder::new(file)?),
+        Compression::None => Box::new(file),
+    })
+}
+
+/// Creates `path` for writing, wrapping it in the matching encoder. The returned
+/// writer flushes and finalises the stream when dropped.
+pub fn create_writer(path: &str, compression: Compression) -> Result<Box<dyn std::io::Write>, std::io::Error> {
+    let file = std::fs::File::create(path)?;
+    Ok(match compression {
+        Compression::Gzip => Box::new(flate2::write::GzEncoder::new(file, flate2::Compression::default())),
+        Compression::Zstd => Box::new(zstd::stream::write::Encoder::new(file, 0)?.auto_finish()),
+        Compression::None => Box::new(file),
+    })
+}
+
+/// SQL type declared for a column through the `--schema` argument.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ColumnType {
+    Int,
+    Real,
+    Text,
+    Bool,
+    Date,
+    Timestamp,
+}
+
+/// A single column declaration parsed from `--schema`.
+#[derive(Clone)]
+pub struct Column {
+    pub name     : String,
+    pub col_type : ColumnType,
+    pub nullable : bool,
+}
+
+/// Parses a `--schema` specification such as `id:INT,name:TEXT NOT NULL,active:BOOL`
+/// into an ordered list of column declarations. Columns are nullable unless the
+/// declaration contains `NOT NULL`.
+pub fn parse_schema(spec: &str) -> Vec<Column> {
+    let mut columns: Vec<Column> = Vec::new();
+
+    for field in spec.split(',') {
+        let field = field.trim();
+        if field.is_empty() {
+            continue;
+        }
+
+        let (name, declaration) = match field.split_once(':') {
+            Some((n, d)) => (n.trim(), d.trim()),
+            None         => (field, "TEXT"),
+        };
+
+        let nullable = !declaration.to_uppercase().contains("NOT NULL");
+        let col_type = match declaration.split_whitespace().next().unwrap_or("TEXT").to_uppercase().as_str() {
+            "INT" | "INTEGER"       => ColumnType::Int,
+            "REAL" | "FLOAT" | "DOUBLE" => ColumnType::Real,
+            "BOOL" | "BOOLEAN"      => ColumnType::Bool,
+            "DATE"                  => ColumnType::Date,
+            "TIMESTAMP" | "DATETIME" => ColumnType::Timestamp,
+            _                       => ColumnType::Text,
+        };
+
+        columns.push(Column { name: name.to_string(), col_type, nullable });
+    }
+
+    columns
+}
+
+impl Arguments {
+    pub fn new_from_console(matches: clap::ArgMatches) -> Arguments {
+        let source = matches.value_of("csv").unwrap().to_string();
+
+        let delimiter = match matches.value_of("delimiter").unwrap() {
+            "semicolon" => b';',
+            "tab"       => b'\t',
+            _           => b',',
+        };
+
+        let table = match matches.value_of("table") {
+            Some(t) => t.to_string(),
+            None    => file_stem(source.as_str()),
+        };
+
+        let target = match matches.value_of("sql") {
+            Some(s) => s.to_string(),
+            None    => format!("{}.sql", table),
+        };
+
+        let columns = match matches.values_of("columns") {
+            Some(values) => values.map(|v| v.to_string()).collect(),
+            None         => Vec::new(),
+        };
+
+        let schema = match matches.value_of("schema") {
+            Some(spec) => parse_schema(spec),
+            None       => Vec::new(),
+        };
+
+        Arguments {
+            source,
+            target,
+            target_type        : matches.value_of("target").unwrap_or("sql").to_string(),
+            delimiter,
+            has_headers        : matches.value_of("headers").unwrap() == "true",
+            table,
+            columns,
+            chunk              : matches.value_of("chunk").unwrap().parse().unwrap_or(0),
+            chunk_insert       : matches.value_of("chunk_insert").unwrap().parse().unwrap_or(0),
+            prefix             : matches.value_of("prefix").unwrap_or("").to_string(),
+            suffix             : matches.value_of("suffix").unwrap_or("").to_string(),
+            with_transaction   : matches.value_of("with_transaction").unwrap() == "true",
+            typed              : matches.value_of("typed").unwrap() == "true",
+            schema,
+            bool_as_int        : matches.value_of("bool_as_int").map(|v| v == "true").unwrap_or(false),
+            db                 : matches.value_of("db").unwrap_or("").to_string(),
+            infer              : matches.value_of("infer").map(|v| v == "true").unwrap_or(false),
+            ndjson             : matches.value_of("ndjson").map(|v| v == "true").unwrap_or(false),
+            source_compression : parse_compression(matches.value_of("source_compression")),
+            target_compression : parse_compression(matches.value_of("target_compression")),
+            trim               : matches.value_of("trim").unwrap_or("none").to_string(),
+            skip_lines         : matches.value_of("skip_lines").unwrap_or("0").parse().unwrap_or(0),
+            comment            : matches.value_of("comment").and_then(|s| s.bytes().next()),
+            quote              : matches.value_of("quote").and_then(|s| s.bytes().next()),
+            flexible           : matches.value_of("flexible").map(|v| v == "true").unwrap_or(false),
+            fast               : matches.value_of("fast").map(|v| v == "true").unwrap_or(true),
+        }
+    }
+}
+
+/// Selects the target implementation requested on the command line and runs the
+/// conversion. Unknown targets fall back to the SQL file target.
+pub fn process_csv(args: Arguments) -> Result<(), std::io::Error> {
+    let target: Box<dyn target::Target> = match args.target_type.as_str() {
+        "csv" => Box::new(target::csv::TargetCsv {}),
+        "db"   => Box::new(target::db::TargetDb {}),
+        "json" => Box::new(target::json::TargetJson {}),
+        _      => Box::new(target::sql::TargetSql {}),
+    };
+
+    target.convert(args)
+}
+
+/// Returns the file name of `path` without its directory or extension, used as the
+/// default table and SQL file name.
+fn file_stem(path: &str) -> String {
+    std::path::Path::new(path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("table")
+        .to_string()
 }
 
 pub mod target {
@@ -34,8 +241,14 @@ pub mod target {
         use tinytemplate::TinyTemplate;
         use itertools::intersperse;
         use crate::Arguments;
+        use crate::{Column, ColumnType, Compression};
         use crate::target::Target;
 
+        /// CSV reader over a possibly-decompressed byte stream.
+        pub type SourceReader = csv::Reader<io::BufReader<Box<dyn io::Read>>>;
+        /// Buffered writer over a possibly-compressed SQL file.
+        pub type SinkWriter = BufWriter<Box<dyn io::Write>>;
+
         pub struct TargetSql {}
 
         impl Target for TargetSql {
@@ -43,88 +256,234 @@ pub mod target {
                 if !Path::new(args.source.as_str()).exists() {
                     return Err(io::Error::new(io::ErrorKind::NotFound, "CSV file not found"));
                 }
-        
-                let csv_file = File::open(args.source.clone())?;
-                let reader = io::BufReader::new(csv_file);
-                let csv_reader = csv::ReaderBuilder::new()
-                            .has_headers(args.has_headers)
-                            .from_reader(reader);
-        
+
+                let compression = args.source_compression.unwrap_or_else(|| Compression::detect(args.source.as_str()));
+                let mut reader = io::BufReader::new(crate::open_reader(args.source.as_str(), compression)?);
+                skip_leading_lines(&mut reader, args.skip_lines)?;
+                let csv_reader = reader_builder(&args).from_reader(reader);
+
                 generate_sql_file(args, csv_reader)
             }
         }
 
-        pub fn generate_sql_file(args: Arguments, csv_reader: csv::Reader<io::BufReader<File>>) -> Result<(), io::Error> {
-            let sql_file = File::create(&args.target).expect("Unable to create sql file");
+        /// Builds a `csv::ReaderBuilder` configured with the robustness knobs shared
+        /// by every target: delimiter, headers, trimming, comment and quote
+        /// characters, and flexible field counts.
+        pub(crate) fn reader_builder(args: &Arguments) -> csv::ReaderBuilder {
+            let mut builder = csv::ReaderBuilder::new();
+            builder.has_headers(args.has_headers)
+                   .delimiter(args.delimiter)
+                   .flexible(args.flexible)
+                   .trim(match args.trim.as_str() {
+                       "headers" => csv::Trim::Headers,
+                       "fields"  => csv::Trim::Fields,
+                       "all"     => csv::Trim::All,
+                       _         => csv::Trim::None,
+                   });
+            if let Some(comment) = args.comment {
+                builder.comment(Some(comment));
+            }
+            if let Some(quote) = args.quote {
+                builder.quote(quote);
+            }
+            builder
+        }
+
+        /// Discards `count` leading lines from the reader before the CSV parser sees
+        /// them, used to drop junk rows ahead of the real header.
+        pub(crate) fn skip_leading_lines<R: io::BufRead>(reader: &mut R, count: usize) -> Result<(), io::Error> {
+            let mut discarded = String::new();
+            for _ in 0..count {
+                discarded.clear();
+                if reader.read_line(&mut discarded)? == 0 {
+                    break;
+                }
+            }
+            Ok(())
+        }
+
+        pub fn generate_sql_file(args: Arguments, csv_reader: SourceReader) -> Result<(), io::Error> {
+            let compression = args.target_compression.unwrap_or_else(|| Compression::detect(args.target.as_str()));
+            let sql_file = crate::create_writer(args.target.as_str(), compression).expect("Unable to create sql file");
             let mut writer = BufWriter::new(sql_file);
     
             let context = &TemplateContext {
                 table: args.table.to_string()
             };
-            append_file_content(args.prefix.clone(), context, &mut writer)?;
+            // `--infer` scans the records once to discover the schema and emits a
+            // generated `create table`, taking the place of the `--prefix` file.
+            if args.infer {
+                let columns = infer_schema(&args)?;
+                write_create_table(&args, &columns, &mut writer)?;
+            } else {
+                append_file_content(args.prefix.clone(), context, &mut writer)?;
+            }
             generate_sql(&args, csv_reader, &mut writer)?;
             append_file_content(args.suffix, context, &mut writer)?;
     
             Ok(())
         }
     
-        fn generate_sql(args: &Arguments, mut csv_reader: csv::Reader<io::BufReader<File>>, writer: &mut BufWriter<File>) -> Result<(), io::Error> {
-            let insert_fields = format_fields(get_fields(args, csv_reader.headers()?));
-    
-            let mut chunk_count = 0;
-            let mut chunk_insert_count = 0;
-            let mut insert_separator = ";\n\n";
-    
-            if args.with_transaction {
-                write!(writer, "begin transaction")?;
-            } else {
-                insert_separator = "";
+        fn generate_sql(args: &Arguments, csv_reader: SourceReader, writer: &mut SinkWriter) -> Result<(), io::Error> {
+            // The byte path skips UTF-8 validation and per-field `String`
+            // allocation. It is exact for the untyped case (plain quoting), so `--fast`
+            // defaults to true and takes it there; pass `--fast false` to force the
+            // string path instead. Typed/`--schema` output always needs string parsing
+            // to detect numbers, booleans and validate values, so `--fast` is ignored
+            // in that case rather than silently re-stringifying or skipping
+            // validation.
+            if args.fast && !args.typed && args.schema.is_empty() {
+                return generate_sql_bytes(args, csv_reader, writer);
             }
-    
-            for record in csv_reader.records() {
-                if chunk_insert_count == 0 {
-                    if args.chunk > 0 && chunk_count == args.chunk {
+
+            generate_sql_strings(args, csv_reader, writer)
+        }
+
+        /// `begin transaction`/chunk/`commit` bookkeeping shared by the string and
+        /// byte generation paths, so the two can't drift on how batches are chunked.
+        struct ChunkedInserts<'a> {
+            args: &'a Arguments,
+            insert_fields: String,
+            chunk_count: usize,
+            chunk_insert_count: usize,
+            insert_separator: &'static str,
+        }
+
+        impl<'a> ChunkedInserts<'a> {
+            fn new(args: &'a Arguments, insert_fields: String) -> Self {
+                ChunkedInserts { args, insert_fields, chunk_count: 0, chunk_insert_count: 0, insert_separator: ";\n\n" }
+            }
+
+            fn begin(&mut self, writer: &mut SinkWriter) -> Result<(), io::Error> {
+                if self.args.with_transaction {
+                    write!(writer, "begin transaction")?;
+                } else {
+                    self.insert_separator = "";
+                }
+                Ok(())
+            }
+
+            /// Writes the `insert into ... values` preamble for the next record,
+            /// opening a new chunk transaction first if the current one is full.
+            fn before_record(&mut self, writer: &mut SinkWriter) -> Result<(), io::Error> {
+                if self.chunk_insert_count == 0 {
+                    if self.args.chunk > 0 && self.chunk_count == self.args.chunk {
                         write!(writer, ";\n\ncommit;\n\nbegin transaction")?;
-                        chunk_count = 0;
+                        self.chunk_count = 0;
                     }
-    
-                    write!(writer, "{}insert into {} {} values", insert_separator, args.table.as_str(), insert_fields)?;
-                    insert_separator = "";
-                    chunk_count += 1;
+
+                    write!(writer, "{}insert into {} {} values", self.insert_separator, self.args.table.as_str(), self.insert_fields)?;
+                    self.insert_separator = "";
+                    self.chunk_count += 1;
                 }
-    
+                Ok(())
+            }
+
+            /// Advances the insert-chunk bookkeeping once a record's values have been
+            /// written, picking the separator for the next one.
+            fn after_record(&mut self) {
+                if self.args.chunk_insert > 0 {
+                    self.chunk_insert_count += 1;
+                    self.insert_separator = ",";
+                    if self.args.chunk_insert == self.chunk_insert_count {
+                        self.chunk_insert_count = 0;
+                        self.insert_separator = ";\n\n";
+                    }
+                } else {
+                    self.insert_separator = ";\n\n";
+                }
+            }
+
+            fn finish(&self, writer: &mut SinkWriter) -> Result<(), io::Error> {
+                if self.args.with_transaction {
+                    write!(writer, ";\n\ncommit;")?
+                } else {
+                    write!(writer, ";")?
+                }
+                Ok(())
+            }
+        }
+
+        fn generate_sql_strings(args: &Arguments, mut csv_reader: SourceReader, writer: &mut SinkWriter) -> Result<(), io::Error> {
+            let insert_fields = format_fields(get_fields(args, csv_reader.headers()?));
+            let mut inserts = ChunkedInserts::new(args, insert_fields);
+            inserts.begin(writer)?;
+
+            for record in csv_reader.records() {
+                inserts.before_record(writer)?;
+
                 match record {
-                    Ok(row) => write!(writer, "{}\n{}", insert_separator, get_values(args, &row))?,
+                    Ok(row) => write!(writer, "{}\n{}", inserts.insert_separator, get_values(args, &row)?)?,
                     Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e))
                 }
-    
-                if args.chunk_insert > 0 {
-                    chunk_insert_count += 1;
-                    insert_separator = ",";
-                    if args.chunk_insert == chunk_insert_count {
-                        chunk_insert_count = 0;
-                        insert_separator = ";\n\n";
-                    }
-                } else {
-                    insert_separator = ";\n\n";
+
+                inserts.after_record();
+            }
+
+            inserts.finish(writer)
+        }
+
+        fn generate_sql_bytes(args: &Arguments, mut csv_reader: SourceReader, writer: &mut SinkWriter) -> Result<(), io::Error> {
+            let insert_fields = format_fields(get_fields(args, csv_reader.headers()?));
+            let mut inserts = ChunkedInserts::new(args, insert_fields);
+            inserts.begin(writer)?;
+
+            // Reuse a single `ByteRecord` across the whole file so the field bytes
+            // buffer is amortised instead of reallocated per row.
+            let mut record = csv::ByteRecord::new();
+            loop {
+                match csv_reader.read_byte_record(&mut record) {
+                    Ok(false)  => break,
+                    Ok(true)   => {}
+                    Err(e)     => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
                 }
+
+                inserts.before_record(writer)?;
+
+                writeln!(writer, "{}", inserts.insert_separator)?;
+                write_byte_values(writer, &record)?;
+
+                inserts.after_record();
             }
-    
-            if args.with_transaction {
-                write!(writer, ";\n\ncommit;")?
-            } else {
-                write!(writer, ";")?
+
+            inserts.finish(writer)
+        }
+
+        fn write_byte_values(writer: &mut SinkWriter, record: &csv::ByteRecord) -> Result<(), io::Error> {
+            writer.write_all(b"(")?;
+            let mut separator: &[u8] = b"";
+            for field in record.iter() {
+                writer.write_all(separator)?;
+                writer.write_all(b"'")?;
+                write_escaped(writer, field)?;
+                writer.write_all(b"'")?;
+                separator = b", ";
             }
-    
+            writer.write_all(b")")?;
             Ok(())
         }
-    
+
+        /// Writes `field` to `writer`, doubling any single quote (`'` -> `''`) at the
+        /// byte level without allocating an intermediate `String`.
+        fn write_escaped(writer: &mut SinkWriter, field: &[u8]) -> Result<(), io::Error> {
+            let mut start = 0;
+            for (index, &byte) in field.iter().enumerate() {
+                if byte == b'\'' {
+                    writer.write_all(&field[start..=index])?;
+                    writer.write_all(b"'")?;
+                    start = index + 1;
+                }
+            }
+            writer.write_all(&field[start..])?;
+            Ok(())
+        }
+
         #[derive(Serialize)]
         struct TemplateContext {
             table: String,
         }
     
-        fn append_file_content(path: String, context: &TemplateContext, writer: &mut BufWriter<File>) -> Result<(), io::Error> {
+        fn append_file_content(path: String, context: &TemplateContext, writer: &mut SinkWriter) -> Result<(), io::Error> {
             if !Path::new(path.as_str()).exists() {
                 return Ok(());
             }
@@ -135,7 +494,7 @@ pub mod target {
     
             for line in reader.lines() {
                 template.push_str(line.unwrap().as_str());
-                template.push_str("\n");
+                template.push('\n');
             }
     
             let mut tt = TinyTemplate::new();
@@ -152,15 +511,105 @@ pub mod target {
             Ok(())
         }
     
-        fn get_fields(args: &Arguments, headers: &csv::StringRecord) -> Vec<String> {
+        /// Makes a first pass over the source CSV, inferring for each column the
+        /// narrowest type that fits every non-empty value and whether any cell was
+        /// empty. `csv::Reader` is single-pass, so the file is reopened here rather
+        /// than buffering every record, keeping memory bounded for large inputs.
+        fn infer_schema(args: &Arguments) -> Result<Vec<Column>, io::Error> {
+            let compression = args.source_compression.unwrap_or_else(|| Compression::detect(args.source.as_str()));
+            let mut reader = io::BufReader::new(crate::open_reader(args.source.as_str(), compression)?);
+            skip_leading_lines(&mut reader, args.skip_lines)?;
+            let mut csv_reader = reader_builder(args).from_reader(reader);
+
+            let fields = get_fields(args, csv_reader.headers()?);
+            let mut all_int     = vec![true; fields.len()];
+            let mut all_numeric = vec![true; fields.len()];
+            let mut all_bool    = vec![true; fields.len()];
+            let mut seen        = vec![false; fields.len()];
+            let mut nullable    = vec![false; fields.len()];
+
+            for record in csv_reader.records() {
+                let row = match record {
+                    Ok(row) => row,
+                    Err(e)  => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+                };
+
+                for (index, value) in row.iter().enumerate() {
+                    if index >= fields.len() {
+                        continue;
+                    }
+                    if value.is_empty() {
+                        nullable[index] = true;
+                        continue;
+                    }
+                    seen[index]        = true;
+                    all_int[index]     &= value.parse::<i64>().is_ok();
+                    all_numeric[index] &= is_number(value);
+                    all_bool[index]    &= is_boolean(value.to_string());
+                }
+            }
+
+            let columns = fields.into_iter().enumerate().map(|(index, name)| {
+                let col_type = widen_inferred_type(seen[index], all_int[index], all_numeric[index], all_bool[index]);
+                Column { name, col_type, nullable: nullable[index] }
+            }).collect();
+
+            Ok(columns)
+        }
+
+        /// Picks the narrowest `ColumnType` consistent with every non-empty value
+        /// seen for a column, in `Int` -> `Real` -> `Bool` -> `Text` precedence. A
+        /// column with no non-empty values at all (`seen == false`) is `Text`.
+        fn widen_inferred_type(seen: bool, all_int: bool, all_numeric: bool, all_bool: bool) -> ColumnType {
+            if !seen {
+                ColumnType::Text
+            } else if all_int {
+                ColumnType::Int
+            } else if all_numeric {
+                ColumnType::Real
+            } else if all_bool {
+                ColumnType::Bool
+            } else {
+                ColumnType::Text
+            }
+        }
+
+        fn write_create_table(args: &Arguments, columns: &[Column], writer: &mut SinkWriter) -> Result<(), io::Error> {
+            writeln!(writer, "create table {} (", args.table)?;
+            let mut separator = "";
+            for column in columns {
+                let not_null = if column.nullable { "" } else { " not null" };
+                write!(writer, "{}  {} {}{}", separator, column.name, type_name(column.col_type), not_null)?;
+                separator = ",\n";
+            }
+            writeln!(writer, "\n);\n")?;
+            Ok(())
+        }
+
+        fn type_name(col_type: ColumnType) -> &'static str {
+            match col_type {
+                ColumnType::Int       => "INTEGER",
+                ColumnType::Real      => "REAL",
+                ColumnType::Text      => "TEXT",
+                ColumnType::Bool      => "BOOLEAN",
+                ColumnType::Date      => "DATE",
+                ColumnType::Timestamp => "TIMESTAMP",
+            }
+        }
+
+        pub(crate) fn get_fields(args: &Arguments, headers: &csv::StringRecord) -> Vec<String> {
             let mut fields: Vec<String> = Vec::new();
-            if args.columns.is_empty() && args.has_headers {
+            if !args.columns.is_empty() {
+                for column in &args.columns {
+                    fields.push(column.to_string());
+                }
+            } else if args.has_headers {
                 for header in headers {
                     fields.push(header.to_string());
                 }
             } else {
-                for column in &args.columns {
-                    fields.push(column.to_string());
+                for column in &args.schema {
+                    fields.push(column.name.clone());
                 }
             }
             fields
@@ -171,69 +620,240 @@ pub mod target {
             format!("({})", insert_fields)
         }
 
-        fn get_values(args: &Arguments, record: &csv::StringRecord) -> String {
+        fn get_values(args: &Arguments, record: &csv::StringRecord) -> Result<String, io::Error> {
             let mut values = String::new();
             let mut separator = "";
-    
-            for result in record {
+
+            for (index, result) in record.iter().enumerate() {
                 values.push_str(separator);
-                if args.typed {
+                if let Some(column) = args.schema.get(index) {
+                    values.push_str(&get_schema_value(column, result, args.bool_as_int)?);
+                } else if args.typed {
                     values.push_str(&get_value(result));
                 } else {
-                    values.push_str("'");
+                    values.push('\'');
                     values.push_str(&result.replace("'", "''"));
-                    values.push_str("'");
+                    values.push('\'');
                 }
                 separator = ", "
             }
-    
-            format!("({})", values)
+
+            Ok(format!("({})", values))
+        }
+
+        fn get_schema_value(column: &Column, result: &str, bool_as_int: bool) -> Result<String, io::Error> {
+            if result.is_empty() {
+                if column.nullable {
+                    return Ok(String::from("NULL"));
+                }
+                return Err(io::Error::new(io::ErrorKind::InvalidData,
+                    format!("empty value in non-nullable column `{}`", column.name)));
+            }
+
+            match column.col_type {
+                ColumnType::Int => {
+                    if result.parse::<i64>().is_err() {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData,
+                            format!("invalid integer `{}` in column `{}`", result, column.name)));
+                    }
+                    Ok(result.to_string())
+                }
+                ColumnType::Real => {
+                    if result.parse::<f64>().is_err() {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData,
+                            format!("invalid real `{}` in column `{}`", result, column.name)));
+                    }
+                    Ok(result.to_string())
+                }
+                ColumnType::Text => Ok(format!("'{}'", result.replace("'", "''"))),
+                ColumnType::Bool => {
+                    let truthy = matches!(result.to_lowercase().as_str(), "true" | "t" | "1" | "yes" | "y");
+                    Ok(if bool_as_int {
+                        String::from(if truthy { "1" } else { "0" })
+                    } else {
+                        String::from(if truthy { "TRUE" } else { "FALSE" })
+                    })
+                }
+                ColumnType::Date => {
+                    if !is_date(result) {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData,
+                            format!("invalid date `{}` in column `{}`", result, column.name)));
+                    }
+                    Ok(format!("'{}'", result.replace("'", "''")))
+                }
+                ColumnType::Timestamp => {
+                    if !is_timestamp(result) {
+                        return Err(io::Error::new(io::ErrorKind::InvalidData,
+                            format!("invalid timestamp `{}` in column `{}`", result, column.name)));
+                    }
+                    Ok(format!("'{}'", result.replace("'", "''")))
+                }
+            }
+        }
+
+        fn is_leap_year(year: u32) -> bool {
+            (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+        }
+
+        /// Validates a `YYYY-MM-DD` date, including the day-of-month range for the
+        /// given month and year (so e.g. `2024-02-30` is rejected).
+        fn is_date(str: &str) -> bool {
+            let parts: Vec<&str> = str.split('-').collect();
+            if parts.len() != 3
+                || parts[0].len() != 4
+                || parts[1].len() != 2
+                || parts[2].len() != 2
+                || parts.iter().any(|p| !p.chars().all(|c| c.is_ascii_digit()))
+            {
+                return false;
+            }
+
+            let year  = parts[0].parse::<u32>().unwrap_or(0);
+            let month = parts[1].parse::<u32>().unwrap_or(0);
+            let day   = parts[2].parse::<u32>().unwrap_or(0);
+
+            let days_in_month = match month {
+                1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+                4 | 6 | 9 | 11              => 30,
+                2                           => if is_leap_year(year) { 29 } else { 28 },
+                _                           => return false,
+            };
+
+            (1..=days_in_month).contains(&day)
+        }
+
+        /// Validates an `HH:MM:SS` time (an optional fractional-seconds suffix such
+        /// as `.123` is tolerated), range-checking hours, minutes and seconds.
+        fn is_time(str: &str) -> bool {
+            let parts: Vec<&str> = str.split(':').collect();
+            if parts.len() != 3 {
+                return false;
+            }
+
+            let hour   = match parts[0].parse::<u32>() { Ok(h) => h, Err(_) => return false };
+            let minute = match parts[1].parse::<u32>() { Ok(m) => m, Err(_) => return false };
+            let second = match parts[2].split('.').next().unwrap_or("").parse::<u32>() { Ok(s) => s, Err(_) => return false };
+
+            hour < 24 && minute < 60 && second < 60
+        }
+
+        /// Validates a `YYYY-MM-DD[ T]HH:MM:SS` timestamp: both the date and the
+        /// time component must be present and in range.
+        fn is_timestamp(str: &str) -> bool {
+            let mut parts = str.splitn(2, [' ', 'T']);
+            let date = parts.next().unwrap_or("");
+            match parts.next() {
+                Some(time) => is_date(date) && is_time(time),
+                None       => false,
+            }
         }
     
         fn get_value(result: &str) -> String {
             let mut value = String::new();
-    
-            if is_number(result) {
-                value.push_str(result);
-            } else if is_boolean(String::from(result)) {
+
+            if is_number(result) || is_boolean(String::from(result)) {
                 value.push_str(result);
+            } else if result.is_empty() {
+                value.push_str("NULL");
             } else {
-                if result.is_empty() {
-                    value.push_str("NULL");
-                } else {
-                    value.push_str("'");
-                    value.push_str(&result.replace("'", "''"));
-                    value.push_str("'");
-                }
+                value.push('\'');
+                value.push_str(&result.replace("'", "''"));
+                value.push('\'');
             }
-    
+
             value
         }
-    
-        fn is_number(str: &str) -> bool {
+
+        pub(crate) fn is_number(str: &str) -> bool {
             if str.is_empty() {
                 return false;
             }
-    
-            let test = str.parse::<f64>();
-    
-            return match test {
-                Ok(_) => true,
-                Err(_) => false,
-            }
+
+            str.parse::<f64>().is_ok()
         }
-    
-        fn is_boolean(str: String) -> bool {
+
+        pub(crate) fn is_boolean(str: String) -> bool {
             let tr = "true";
             let fs = "false";
-    
-            return tr.eq(&str.to_lowercase()) || fs.eq(&str.to_lowercase());
+
+            tr.eq(&str.to_lowercase()) || fs.eq(&str.to_lowercase())
+        }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn date_accepts_valid_calendar_dates() {
+                assert!(is_date("2024-02-29")); // leap year
+                assert!(is_date("2023-01-31"));
+            }
+
+            #[test]
+            fn date_rejects_out_of_range_month_or_day() {
+                assert!(!is_date("2024-13-01"));
+                assert!(!is_date("2024-02-30"));
+                assert!(!is_date("2023-02-29")); // not a leap year
+                assert!(!is_date("2024-00-10"));
+                assert!(!is_date("2024-01-00"));
+            }
+
+            #[test]
+            fn date_rejects_malformed_shapes() {
+                assert!(!is_date("2024-1-1"));
+                assert!(!is_date("not-a-date"));
+                assert!(!is_date(""));
+            }
+
+            #[test]
+            fn time_accepts_in_range_values_with_optional_fraction() {
+                assert!(is_time("00:00:00"));
+                assert!(is_time("23:59:59"));
+                assert!(is_time("12:30:00.123"));
+            }
+
+            #[test]
+            fn time_rejects_out_of_range_or_malformed_values() {
+                assert!(!is_time("24:00:00"));
+                assert!(!is_time("12:60:00"));
+                assert!(!is_time("12:00:60"));
+                assert!(!is_time("12:00"));
+            }
+
+            #[test]
+            fn timestamp_requires_both_date_and_time() {
+                assert!(is_timestamp("2024-01-01 12:30:00"));
+                assert!(is_timestamp("2024-01-01T12:30:00"));
+                assert!(!is_timestamp("2024-01-01"));
+                assert!(!is_timestamp("2024-01-01 99:99:99"));
+                assert!(!is_timestamp("2024-13-99 12:30:00"));
+            }
+
+            #[test]
+            fn schema_value_validates_int_and_real_before_emitting_unquoted() {
+                let int_column = Column { name: "n".to_string(), col_type: ColumnType::Int, nullable: false };
+                assert_eq!(get_schema_value(&int_column, "42", false).unwrap(), "42");
+                assert!(get_schema_value(&int_column, "42.5", false).is_err());
+                assert!(get_schema_value(&int_column, "1); DROP TABLE x;--", false).is_err());
+
+                let real_column = Column { name: "r".to_string(), col_type: ColumnType::Real, nullable: false };
+                assert_eq!(get_schema_value(&real_column, "42.5", false).unwrap(), "42.5");
+                assert!(get_schema_value(&real_column, "not-a-number", false).is_err());
+            }
+
+            #[test]
+            fn widen_inferred_type_picks_the_narrowest_consistent_type() {
+                assert_eq!(widen_inferred_type(false, true, true, true), ColumnType::Text);
+                assert_eq!(widen_inferred_type(true, true, true, true), ColumnType::Int);
+                assert_eq!(widen_inferred_type(true, false, true, true), ColumnType::Real);
+                assert_eq!(widen_inferred_type(true, false, false, true), ColumnType::Bool);
+                assert_eq!(widen_inferred_type(true, false, false, false), ColumnType::Text);
+            }
         }
     }
 
     pub mod csv {
         use std::io;
-        use std::fs::File;
         use std::path::Path;
         use crate::target::Target;
         use crate::target::sql;
@@ -247,14 +867,198 @@ pub mod target {
                     return Err(io::Error::new(io::ErrorKind::NotFound, "CSV file not found"));
                 }
         
-                let csv_file = File::open(args.source.clone())?;
-                let reader = io::BufReader::new(csv_file);
-                let csv_reader = csv::ReaderBuilder::new()
-                            .has_headers(args.has_headers)
-                            .from_reader(reader);
-        
+                let compression = args.source_compression.unwrap_or_else(|| crate::Compression::detect(args.source.as_str()));
+                let mut reader = io::BufReader::new(crate::open_reader(args.source.as_str(), compression)?);
+                sql::skip_leading_lines(&mut reader, args.skip_lines)?;
+                let csv_reader = sql::reader_builder(&args).from_reader(reader);
+
                 sql::generate_sql_file(args, csv_reader)
             }
         }
     }
+
+    pub mod db {
+        use std::io;
+        use std::io::BufReader;
+        use std::path::Path;
+        use rusqlite::Connection;
+        use rusqlite::types::Value;
+        use crate::Arguments;
+        use crate::target::Target;
+        use crate::target::sql;
+
+        pub struct TargetDb {}
+
+        impl Target for TargetDb {
+            fn convert(&self, args: Arguments) -> Result<(), io::Error> {
+                if !Path::new(args.source.as_str()).exists() {
+                    return Err(io::Error::new(io::ErrorKind::NotFound, "CSV file not found"));
+                }
+
+                let compression = args.source_compression.unwrap_or_else(|| crate::Compression::detect(args.source.as_str()));
+                let mut reader = BufReader::new(crate::open_reader(args.source.as_str(), compression)?);
+                sql::skip_leading_lines(&mut reader, args.skip_lines)?;
+                let mut csv_reader = sql::reader_builder(&args).from_reader(reader);
+
+                insert_records(&args, &mut csv_reader)
+            }
+        }
+
+        fn insert_records(args: &Arguments, csv_reader: &mut csv::Reader<BufReader<Box<dyn io::Read>>>) -> Result<(), io::Error> {
+            let mut connection = Connection::open(&args.db).map_err(to_io_error)?;
+
+            let fields = sql::get_fields(args, csv_reader.headers()?);
+            let placeholders = vec!["?"; fields.len()].join(", ");
+            let statement_sql = format!("insert into {} ({}) values ({})",
+                                        args.table, fields.join(", "), placeholders);
+
+            let chunk = if args.chunk > 0 { args.chunk } else { usize::MAX };
+            let mut transaction = connection.transaction().map_err(to_io_error)?;
+            let mut count = 0;
+
+            for record in csv_reader.records() {
+                let row = match record {
+                    Ok(row) => row,
+                    Err(e)  => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+                };
+
+                let values: Vec<Value> = row.iter().map(|field| to_value(args, field)).collect();
+
+                {
+                    let mut statement = transaction.prepare_cached(statement_sql.as_str()).map_err(to_io_error)?;
+                    statement.execute(rusqlite::params_from_iter(values.iter())).map_err(to_io_error)?;
+                }
+
+                count += 1;
+                if count % chunk == 0 {
+                    transaction.commit().map_err(to_io_error)?;
+                    transaction = connection.transaction().map_err(to_io_error)?;
+                }
+            }
+
+            transaction.commit().map_err(to_io_error)?;
+
+            Ok(())
+        }
+
+        fn to_value(args: &Arguments, field: &str) -> Value {
+            if field.is_empty() {
+                return Value::Null;
+            }
+
+            if args.typed {
+                if let Ok(integer) = field.parse::<i64>() {
+                    return Value::Integer(integer);
+                }
+                if sql::is_number(field) {
+                    if let Ok(real) = field.parse::<f64>() {
+                        return Value::Real(real);
+                    }
+                }
+                if sql::is_boolean(field.to_string()) {
+                    return Value::Integer(if field.eq_ignore_ascii_case("true") { 1 } else { 0 });
+                }
+            }
+
+            Value::Text(field.to_string())
+        }
+
+        fn to_io_error(err: rusqlite::Error) -> io::Error {
+            io::Error::other(err)
+        }
+    }
+
+    pub mod json {
+        use std::io;
+        use std::fs::File;
+        use std::io::{BufWriter, Write};
+        use std::path::Path;
+        use serde_json::{Map, Value};
+        use crate::Arguments;
+        use crate::target::Target;
+        use crate::target::sql;
+
+        pub struct TargetJson {}
+
+        impl Target for TargetJson {
+            fn convert(&self, args: Arguments) -> Result<(), io::Error> {
+                if !Path::new(args.source.as_str()).exists() {
+                    return Err(io::Error::new(io::ErrorKind::NotFound, "CSV file not found"));
+                }
+
+                let compression = args.source_compression.unwrap_or_else(|| crate::Compression::detect(args.source.as_str()));
+                let mut reader = io::BufReader::new(crate::open_reader(args.source.as_str(), compression)?);
+                sql::skip_leading_lines(&mut reader, args.skip_lines)?;
+                let mut csv_reader = sql::reader_builder(&args).from_reader(reader);
+
+                generate_json_file(&args, &mut csv_reader)
+            }
+        }
+
+        fn generate_json_file(args: &Arguments, csv_reader: &mut csv::Reader<io::BufReader<Box<dyn io::Read>>>) -> Result<(), io::Error> {
+            let json_file = File::create(&args.target).expect("Unable to create json file");
+            let mut writer = BufWriter::new(json_file);
+
+            let fields = sql::get_fields(args, csv_reader.headers()?);
+            let mut separator = "";
+
+            if !args.ndjson {
+                write!(writer, "[")?;
+            }
+
+            for record in csv_reader.records() {
+                let row = match record {
+                    Ok(row) => row,
+                    Err(e)  => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+                };
+
+                let mut object = Map::new();
+                for (index, field) in row.iter().enumerate() {
+                    let key = match fields.get(index) {
+                        Some(name) => name.clone(),
+                        None       => index.to_string(),
+                    };
+                    object.insert(key, to_json_value(args, field));
+                }
+
+                let rendered = Value::Object(object).to_string();
+                if args.ndjson {
+                    writeln!(writer, "{}", rendered)?;
+                } else {
+                    write!(writer, "{}{}", separator, rendered)?;
+                    separator = ",";
+                }
+            }
+
+            if !args.ndjson {
+                write!(writer, "]")?;
+            }
+
+            Ok(())
+        }
+
+        fn to_json_value(args: &Arguments, field: &str) -> Value {
+            if !args.typed {
+                return Value::String(field.to_string());
+            }
+
+            if field.is_empty() {
+                return Value::Null;
+            }
+
+            if let Ok(integer) = field.parse::<i64>() {
+                return Value::from(integer);
+            }
+            if sql::is_number(field) {
+                if let Ok(real) = field.parse::<f64>() {
+                    return Value::from(real);
+                }
+            }
+            if sql::is_boolean(field.to_string()) {
+                return Value::Bool(field.eq_ignore_ascii_case("true"));
+            }
+
+            Value::String(field.to_string())
+        }
+    }
 }
\ No newline at end of file
@@ -1,6 +1,5 @@
 use clap::{Arg, App};
-
-mod lib;
+use roma::{process_csv, Arguments};
 
 fn main() {
     let matches = App::new("Roma")
@@ -77,11 +76,83 @@ fn main() {
             .default_value("false")
             .value_name("true | false")
             .help("Indicates whether the values type are declared, automatically detected or everything is taken as string."))
+        .arg(Arg::new("target")
+            .long("target")
+            .short('r')
+            .default_value("sql")
+            .value_name("sql | csv | db | json")
+            .help("The kind of output the CSV file is converted to."))
+        .arg(Arg::new("ndjson")
+            .long("ndjson")
+            .short('j')
+            .default_value("false")
+            .value_name("true | false")
+            .help("With `--target json`, emits newline-delimited JSON (one object per line) instead of a single JSON array."))
+        .arg(Arg::new("db")
+            .long("db")
+            .value_name("file")
+            .required_if_eq("target", "db")
+            .help("Relative or absolute path to the SQLite database file used by the `--target db` insertion target."))
+        .arg(Arg::new("fast")
+            .long("fast")
+            .short('a')
+            .default_value("true")
+            .value_name("true | false")
+            .help("Reads raw `ByteRecord`s and copies field bytes straight into the output, skipping UTF-8 validation. Defaults to true for untyped output; pass `--fast false` to force the string path instead. Ignored when `--typed` or `--schema` require type detection."))
+        .arg(Arg::new("trim")
+            .long("trim")
+            .default_value("none")
+            .value_name("none | headers | fields | all")
+            .help("Trims surrounding whitespace from headers, fields, both, or nothing."))
+        .arg(Arg::new("skip_lines")
+            .long("skip-lines")
+            .default_value("0")
+            .value_name("#")
+            .help("Number of leading lines to drop before parsing, useful to skip junk rows ahead of the header."))
+        .arg(Arg::new("comment")
+            .long("comment")
+            .value_name("char")
+            .help("Lines starting with this character are ignored."))
+        .arg(Arg::new("quote")
+            .long("quote")
+            .value_name("char")
+            .help("The quote character used by the CSV file, when different from the double quote."))
+        .arg(Arg::new("flexible")
+            .long("flexible")
+            .default_value("false")
+            .value_name("true | false")
+            .help("Tolerates records with a varying number of fields instead of failing."))
+        .arg(Arg::new("source_compression")
+            .long("source-compression")
+            .short('z')
+            .value_name("gzip | zstd | none")
+            .help("Compression codec used to decompress the source CSV. Auto-detected from the `.gz`/`.zst` extension when omitted."))
+        .arg(Arg::new("target_compression")
+            .long("target-compression")
+            .value_name("gzip | zstd | none")
+            .help("Compression codec used to compress the generated SQL file (`--target sql` and `csv`; JSON output is never compressed). Auto-detected from the `.gz`/`.zst` extension when omitted."))
+        .arg(Arg::new("infer")
+            .long("infer")
+            .short('n')
+            .default_value("false")
+            .value_name("true | false")
+            .help("Scans the CSV once to infer each column's type and emits a generated `create table` statement ahead of the inserts, in place of the `--prefix` file."))
+        .arg(Arg::new("schema")
+            .long("schema")
+            .short('m')
+            .value_name("col:TYPE,...")
+            .help("Declares the SQL type of each column (e.g. `id:INT,name:TEXT,active:BOOL,born:DATE`) so values are formatted per declared type instead of guessed. Also provides the column names when `--headers false` is used."))
+        .arg(Arg::new("bool_as_int")
+            .long("boolasint")
+            .short('b')
+            .default_value("false")
+            .value_name("true | false")
+            .help("Renders boolean columns declared in `--schema` as `1`/`0` instead of `TRUE`/`FALSE`."))
         .get_matches();
 
-    let args = lib::Arguments::new_from_console(matches);
+    let args = Arguments::new_from_console(matches);
 
-    match lib::process_csv(args) {
+    match process_csv(args) {
         Ok(())   => println!("CSV file processed successfully!"),
         Err(err) => println!("Error: {}.", err)
     };